@@ -0,0 +1,202 @@
+use super::{BatteryInfo, BluetoothDevice, MetricsBackend};
+use std::process::Command;
+
+pub struct LinuxBackend;
+
+fn read_sysfs_number(path: &std::path::Path) -> Option<f64> {
+    std::fs::read_to_string(path).ok().and_then(|s| s.trim().parse::<f64>().ok())
+}
+
+impl MetricsBackend for LinuxBackend {
+    fn battery_info(&self) -> BatteryInfo {
+        let mut info = BatteryInfo {
+            percent: -1.0,
+            charging: false,
+            // sysfs doesn't expose a remaining-time estimate directly.
+            time_remaining_minutes: -1,
+            cycle_count: -1,
+            condition: "Unknown".to_string(),
+            max_capacity_percent: -1.0,
+            power_source: "Unknown".to_string(),
+        };
+
+        let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") else { return info };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let supply_type = std::fs::read_to_string(path.join("type")).unwrap_or_default();
+            let supply_type = supply_type.trim();
+
+            if supply_type == "Mains" || supply_type == "USB" {
+                if read_sysfs_number(&path.join("online")) == Some(1.0) {
+                    info.power_source = "AC Power".to_string();
+                }
+                continue;
+            }
+            if supply_type != "Battery" {
+                continue;
+            }
+
+            if let Some(pct) = read_sysfs_number(&path.join("capacity")) {
+                info.percent = pct;
+            }
+            let status = std::fs::read_to_string(path.join("status")).unwrap_or_default();
+            info.charging = status.trim().eq_ignore_ascii_case("Charging");
+            if let Some(cycle_count) = read_sysfs_number(&path.join("cycle_count")) {
+                info.cycle_count = cycle_count as i64;
+            }
+            let full = read_sysfs_number(&path.join("energy_full")).or_else(|| read_sysfs_number(&path.join("charge_full")));
+            let full_design = read_sysfs_number(&path.join("energy_full_design")).or_else(|| read_sysfs_number(&path.join("charge_full_design")));
+            if let (Some(full), Some(full_design)) = (full, full_design) {
+                if full_design > 0.0 {
+                    let max_capacity_percent = (full / full_design) * 100.0;
+                    info.condition = if max_capacity_percent >= 80.0 { "Normal" } else { "Service Recommended" }.to_string();
+                    info.max_capacity_percent = max_capacity_percent;
+                }
+            }
+        }
+        if info.power_source == "Unknown" && info.percent >= 0.0 {
+            info.power_source = if info.charging { "AC Power" } else { "Battery Power" }.to_string();
+        }
+        info
+    }
+
+    fn thermal_fallback(&self) -> String {
+        let mut hottest_millic: Option<i64> = None;
+        if let Ok(entries) = std::fs::read_dir("/sys/class/thermal") {
+            for entry in entries.flatten() {
+                let name = entry.file_name();
+                if !name.to_string_lossy().starts_with("thermal_zone") {
+                    continue;
+                }
+                if let Ok(text) = std::fs::read_to_string(entry.path().join("temp")) {
+                    if let Ok(millic) = text.trim().parse::<i64>() {
+                        hottest_millic = Some(hottest_millic.map_or(millic, |h| h.max(millic)));
+                    }
+                }
+            }
+        }
+        let Some(millic) = hottest_millic else { return "NOMINAL".to_string() };
+        let celsius = millic as f64 / 1000.0;
+        if celsius >= 95.0 { "CRITICAL" } else if celsius >= 85.0 { "HEAVY" } else if celsius >= 70.0 { "MODERATE" } else { "NOMINAL" }.to_string()
+    }
+
+    fn wifi_info(&self) -> String {
+        let output = Command::new("nmcli").arg("-t").arg("-f").arg("ACTIVE,SSID,SIGNAL").arg("d").arg("wifi").output();
+        if let Ok(out) = output {
+            let text = String::from_utf8_lossy(&out.stdout);
+            for line in text.lines() {
+                let parts: Vec<&str> = line.split(':').collect();
+                if parts.len() >= 3 && parts[0] == "yes" && !parts[1].is_empty() {
+                    return format!("{} ({}%)", parts[1], parts[2]);
+                }
+            }
+        }
+        "Not Connected".to_string()
+    }
+
+    fn bluetooth_info(&self) -> (bool, Vec<BluetoothDevice>) {
+        let enabled = Command::new("bluetoothctl")
+            .arg("show")
+            .output()
+            .map(|out| String::from_utf8_lossy(&out.stdout).contains("Powered: yes"))
+            .unwrap_or(false);
+        let mut devices = Vec::new();
+        if enabled {
+            if let Ok(out) = Command::new("bluetoothctl").arg("devices").arg("Connected").output() {
+                for line in String::from_utf8_lossy(&out.stdout).lines() {
+                    // Lines look like "Device AA:BB:CC:DD:EE:FF Device Name"
+                    let mut fields = line.splitn(3, ' ');
+                    let Some(address) = fields.nth(1) else { continue };
+                    let name = fields.next().unwrap_or("Unknown").to_string();
+                    devices.push(describe_bluetooth_device(address, name));
+                }
+            }
+        }
+        (enabled, devices)
+    }
+
+    fn volume_percent(&self) -> i32 {
+        let output = Command::new("pactl").arg("get-sink-volume").arg("@DEFAULT_SINK@").output();
+        if let Ok(out) = output {
+            let text = String::from_utf8_lossy(&out.stdout);
+            if let Some(pct_idx) = text.find('%') {
+                if let Some(slash_idx) = text[..pct_idx].rfind('/') {
+                    if let Ok(vol) = text[slash_idx + 1..pct_idx].trim().parse::<i32>() {
+                        return vol;
+                    }
+                }
+            }
+        }
+        -1
+    }
+
+    fn brightness_percent(&self) -> i32 {
+        if let Ok(entries) = std::fs::read_dir("/sys/class/backlight") {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let brightness = std::fs::read_to_string(path.join("brightness")).ok().and_then(|s| s.trim().parse::<f64>().ok());
+                let max_brightness = std::fs::read_to_string(path.join("max_brightness")).ok().and_then(|s| s.trim().parse::<f64>().ok());
+                if let (Some(b), Some(max)) = (brightness, max_brightness) {
+                    if max > 0.0 {
+                        return ((b / max) * 100.0) as i32;
+                    }
+                }
+            }
+        }
+        -1
+    }
+
+    fn interface_info(&self, name: &str) -> (bool, Option<String>) {
+        let is_up = std::fs::read_to_string(format!("/sys/class/net/{}/operstate", name))
+            .map(|s| s.trim() == "up")
+            .unwrap_or(false);
+
+        let mut ip_addr = None;
+        if let Ok(out) = Command::new("ip").arg("-4").arg("addr").arg("show").arg(name).output() {
+            let text = String::from_utf8_lossy(&out.stdout);
+            for line in text.lines() {
+                if let Some(rest) = line.trim().strip_prefix("inet ") {
+                    if let Some(addr) = rest.split_whitespace().next() {
+                        ip_addr = Some(addr.split('/').next().unwrap_or(addr).to_string());
+                        break;
+                    }
+                }
+            }
+        }
+        (is_up, ip_addr)
+    }
+}
+
+// `bluetoothctl devices Connected` only gives name + address, so fetch the
+// rest with a per-device `bluetoothctl info` call (same shape as the macOS
+// backend parsing per-device sub-blocks out of `system_profiler`).
+fn describe_bluetooth_device(address: &str, name: String) -> BluetoothDevice {
+    let mut device = BluetoothDevice {
+        name,
+        address: Some(address.to_string()),
+        connection_type: "Unknown".to_string(),
+        battery_percent: None,
+        rssi: None,
+    };
+    if let Ok(out) = Command::new("bluetoothctl").arg("info").arg(address).output() {
+        for line in String::from_utf8_lossy(&out.stdout).lines() {
+            let trimmed = line.trim();
+            if let Some(value) = trimmed.strip_prefix("Battery Percentage:") {
+                device.battery_percent = extract_paren_number(value);
+            } else if let Some(value) = trimmed.strip_prefix("RSSI:") {
+                device.rssi = extract_paren_number(value);
+            } else if let Some(value) = trimmed.strip_prefix("Icon:") {
+                device.connection_type = value.trim().to_string();
+            }
+        }
+    }
+    device
+}
+
+// `bluetoothctl info` reports values like "Battery Percentage: 0x50 (80)" —
+// pull the decimal reading out of the parenthesized part.
+fn extract_paren_number(value: &str) -> Option<i32> {
+    let start = value.find('(')?;
+    let end = value[start + 1..].find(')')?;
+    value[start + 1..start + 1 + end].trim().parse::<i32>().ok()
+}
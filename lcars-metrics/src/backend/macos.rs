@@ -0,0 +1,217 @@
+use super::{BatteryInfo, BluetoothDevice, MetricsBackend};
+use std::process::Command;
+
+pub struct MacBackend;
+
+impl MetricsBackend for MacBackend {
+    fn battery_info(&self) -> BatteryInfo {
+        let mut info = BatteryInfo {
+            percent: -1.0,
+            charging: false,
+            time_remaining_minutes: -1,
+            cycle_count: -1,
+            condition: "Unknown".to_string(),
+            max_capacity_percent: -1.0,
+            power_source: "Unknown".to_string(),
+        };
+
+        if let Ok(out) = Command::new("pmset").arg("-g").arg("batt").output() {
+            let text = String::from_utf8_lossy(&out.stdout);
+            for (i, line) in text.lines().enumerate() {
+                if i == 0 {
+                    if let Some(start) = line.find('\'') {
+                        if let Some(end) = line[start + 1..].find('\'') {
+                            info.power_source = line[start + 1..start + 1 + end].to_string();
+                        }
+                    }
+                    continue;
+                }
+                if line.contains('%') {
+                    let parts: Vec<&str> = line.split('\t').collect();
+                    if parts.len() >= 2 {
+                        let fields = parts[1];
+                        let pct_str: String = fields.chars().take_while(|c| c.is_ascii_digit()).collect();
+                        if let Ok(pct) = pct_str.parse::<f64>() {
+                            info.percent = pct;
+                            info.charging = fields.contains("charging") && !fields.contains("discharging");
+                        }
+                        info.time_remaining_minutes = parse_pmset_remaining(fields);
+                    }
+                }
+            }
+        }
+
+        if let Ok(out) = Command::new("system_profiler").arg("SPPowerDataType").output() {
+            let text = String::from_utf8_lossy(&out.stdout);
+            for line in text.lines() {
+                let trimmed = line.trim();
+                if let Some(value) = trimmed.strip_prefix("Cycle Count:") {
+                    if let Ok(count) = value.trim().parse::<i64>() {
+                        info.cycle_count = count;
+                    }
+                } else if let Some(value) = trimmed.strip_prefix("Condition:") {
+                    info.condition = value.trim().to_string();
+                } else if let Some(value) = trimmed.strip_prefix("Maximum Capacity:") {
+                    let pct_str: String = value.trim().chars().take_while(|c| c.is_ascii_digit()).collect();
+                    if let Ok(pct) = pct_str.parse::<f64>() {
+                        info.max_capacity_percent = pct;
+                    }
+                }
+            }
+        }
+
+        info
+    }
+
+    fn thermal_fallback(&self) -> String {
+        let output = Command::new("pmset").arg("-g").arg("therm").output();
+        if let Ok(out) = output {
+            let text = String::from_utf8_lossy(&out.stdout);
+            if text.contains("Normal") { return "NOMINAL".to_string(); }
+            if text.contains("Moderate") { return "MODERATE".to_string(); }
+            if text.contains("Heavy") { return "HEAVY".to_string(); }
+            if text.contains("Critical") { return "CRITICAL".to_string(); }
+        }
+        "NOMINAL".to_string()
+    }
+
+    fn wifi_info(&self) -> String {
+        let output = Command::new("system_profiler").arg("SPAirPortDataType").output();
+        if let Ok(out) = output {
+            let text = String::from_utf8_lossy(&out.stdout);
+            let mut in_current_network = false;
+            for line in text.lines() {
+                let trimmed = line.trim();
+                if trimmed == "Current Network Information:" { in_current_network = true; continue; }
+                if in_current_network {
+                    if trimmed.ends_with(':') && !trimmed.contains("Current Network") {
+                        return trimmed.trim_end_matches(':').to_string();
+                    }
+                }
+            }
+        }
+        "Not Connected".to_string()
+    }
+
+    fn bluetooth_info(&self) -> (bool, Vec<BluetoothDevice>) {
+        let output = Command::new("system_profiler").arg("SPBluetoothDataType").output();
+        if let Ok(out) = output {
+            let text = String::from_utf8_lossy(&out.stdout);
+            let mut enabled = false;
+            let mut devices: Vec<BluetoothDevice> = Vec::new();
+            let mut in_connected = false;
+            let mut current: Option<BluetoothDevice> = None;
+
+            for line in text.lines() {
+                let trimmed = line.trim();
+                if trimmed.contains("State:") && trimmed.contains("On") { enabled = true; }
+                if trimmed.contains("Bluetooth:") && trimmed.contains("On") { enabled = true; }
+                if trimmed == "Connected:" || trimmed.starts_with("Connected:") { in_connected = true; continue; }
+                if !in_connected { continue; }
+
+                if trimmed.is_empty() || trimmed.starts_with("Not Connected:") {
+                    if let Some(dev) = current.take() { devices.push(dev); }
+                    in_connected = false;
+                    continue;
+                }
+                // Device names are the only bare "Label:" lines in this
+                // section — every other field line is "Key: value".
+                if trimmed.ends_with(':') && !trimmed.contains("Address") && !trimmed.contains("Services") {
+                    if let Some(dev) = current.take() { devices.push(dev); }
+                    let name = trimmed.trim_end_matches(':').to_string();
+                    if !name.is_empty() && name != "Yes" && name != "No" {
+                        current = Some(BluetoothDevice {
+                            name,
+                            address: None,
+                            connection_type: "Unknown".to_string(),
+                            battery_percent: None,
+                            rssi: None,
+                        });
+                    }
+                    continue;
+                }
+                if let Some(dev) = current.as_mut() {
+                    if let Some((key, value)) = trimmed.split_once(':') {
+                        let value = value.trim();
+                        match key.trim() {
+                            "Address" => dev.address = Some(value.to_string()),
+                            "Battery Level" => {
+                                let pct_str: String = value.chars().take_while(|c| c.is_ascii_digit()).collect();
+                                dev.battery_percent = pct_str.parse::<i32>().ok();
+                            }
+                            "RSSI" => dev.rssi = value.parse::<i32>().ok(),
+                            "Minor Type" | "Connection" => dev.connection_type = value.to_string(),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            if let Some(dev) = current.take() { devices.push(dev); }
+            return (enabled, devices);
+        }
+        (false, Vec::new())
+    }
+
+    fn volume_percent(&self) -> i32 {
+        let output = Command::new("osascript").arg("-e").arg("output volume of (get volume settings)").output();
+        if let Ok(out) = output {
+            let text = String::from_utf8_lossy(&out.stdout).trim().to_string();
+            if let Ok(vol) = text.parse::<i32>() { return vol; }
+        }
+        -1
+    }
+
+    fn brightness_percent(&self) -> i32 {
+        let output = Command::new("bash").arg("-c").arg("ioreg -c AppleBacklightDisplay -r | grep -i brightness | head -1").output();
+        if let Ok(out) = output {
+            let text = String::from_utf8_lossy(&out.stdout);
+            for part in text.split('=') {
+                let trimmed = part.trim().trim_end_matches('}').trim();
+                if let Ok(val) = trimmed.parse::<f64>() {
+                    if val <= 1.0 { return (val * 100.0) as i32; }
+                    else if val <= 1024.0 { return ((val / 1024.0) * 100.0) as i32; }
+                }
+            }
+        }
+        -1
+    }
+
+    fn interface_info(&self, name: &str) -> (bool, Option<String>) {
+        let output = Command::new("ifconfig").arg(name).output();
+        if let Ok(out) = output {
+            let text = String::from_utf8_lossy(&out.stdout);
+            let mut is_up = false;
+            let mut ip_addr = None;
+            for line in text.lines() {
+                let trimmed = line.trim();
+                if trimmed.starts_with("flags=") {
+                    is_up = trimmed.contains("UP");
+                }
+                if ip_addr.is_none() && trimmed.starts_with("inet ") {
+                    if let Some(addr) = trimmed.split_whitespace().nth(1) {
+                        ip_addr = Some(addr.to_string());
+                    }
+                }
+            }
+            return (is_up, ip_addr);
+        }
+        (false, None)
+    }
+}
+
+// Looks for the `H:MM remaining` token `pmset -g batt` prints after the
+// percentage (e.g. "100%; charged; 0:00 remaining"). `pmset` prints
+// "(no estimate)" while it hasn't settled on one yet, which has no digits
+// to parse and falls through to "unknown" like everything else here.
+fn parse_pmset_remaining(fields: &str) -> i64 {
+    for word in fields.split(';') {
+        let word = word.trim();
+        if let Some((hours, rest)) = word.split_once(':') {
+            let minutes = rest.split_whitespace().next().unwrap_or("");
+            if let (Ok(h), Ok(m)) = (hours.parse::<i64>(), minutes.parse::<i64>()) {
+                return h * 60 + m;
+            }
+        }
+    }
+    -1
+}
@@ -0,0 +1,62 @@
+// Every collector used to hardcode macOS tools (`pmset`, `system_profiler`,
+// `osascript`, `ioreg`), which meant the binary was useless anywhere but
+// macOS despite `sysinfo` itself being portable. Apart from the best-effort
+// `istats` fan-speed probe in main.rs, this module is the one place that
+// still shells out to (or reads) OS-specific sources; callers
+// only ever see `MetricsBackend`, so `get_system_metrics`/`get_comms_status`
+// produce the same JSON schema regardless of platform.
+
+use serde::Serialize;
+
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "linux")]
+mod linux;
+
+#[derive(Serialize)]
+pub struct BluetoothDevice {
+    pub name: String,
+    pub address: Option<String>,
+    pub connection_type: String,
+    pub battery_percent: Option<i32>,
+    pub rssi: Option<i32>,
+}
+
+// Mirrors `pmset`/`system_profiler`'s own vocabulary for "unknown": -1 for
+// numbers that can't be determined, "Unknown" for strings. Keeping both
+// platforms' unknowns consistent means the UI needs exactly one "no data"
+// branch instead of one per platform.
+pub struct BatteryInfo {
+    pub percent: f64,
+    pub charging: bool,
+    pub time_remaining_minutes: i64,
+    pub cycle_count: i64,
+    pub condition: String,
+    pub max_capacity_percent: f64,
+    pub power_source: String,
+}
+
+pub trait MetricsBackend {
+    fn battery_info(&self) -> BatteryInfo;
+    // Only consulted when sysinfo's `Components` can't produce a usable
+    // hottest-vs-critical ratio (see `get_thermal_pressure`).
+    fn thermal_fallback(&self) -> String;
+    fn wifi_info(&self) -> String;
+    fn bluetooth_info(&self) -> (bool, Vec<BluetoothDevice>);
+    fn volume_percent(&self) -> i32;
+    fn brightness_percent(&self) -> i32;
+    // Whether `name` is up, plus its IPv4 address if it has one. Used by
+    // `NetworkSampler` to decide which interfaces to report and resolve the
+    // address alongside each one.
+    fn interface_info(&self, name: &str) -> (bool, Option<String>);
+}
+
+#[cfg(target_os = "macos")]
+pub fn current() -> impl MetricsBackend {
+    macos::MacBackend
+}
+
+#[cfg(target_os = "linux")]
+pub fn current() -> impl MetricsBackend {
+    linux::LinuxBackend
+}
@@ -5,9 +5,18 @@
 // Usage:
 //   lcars-metrics metrics    → system metrics JSON
 //   lcars-metrics comms      → comms status JSON
+//   lcars-metrics watch      → newline-delimited JSON stream (see `run_watch`)
+//   lcars-metrics processes  → top N processes by CPU/memory, JSON array
 
+mod backend;
+
+use backend::MetricsBackend;
 use serde::Serialize;
 use std::process::Command;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Barrier;
+use std::time::Duration;
 use sysinfo::System;
 
 #[derive(Serialize)]
@@ -25,21 +34,105 @@ struct SystemMetrics {
     uptime_seconds: u64,
     battery_percent: f64,
     battery_charging: bool,
+    battery_time_remaining_minutes: i64,
+    battery_cycle_count: i64,
+    battery_condition: String,
+    battery_max_capacity_percent: f64,
+    power_source: String,
     thermal_pressure: String,
+    components: Vec<Component>,
+    fans_rpm: Vec<FanSpeed>,
+    network_interfaces: Vec<NetInterface>,
+}
+
+#[derive(Serialize)]
+struct NetInterface {
+    name: String,
+    ip_addr: Option<String>,
+    rx_bytes_per_sec: f64,
+    tx_bytes_per_sec: f64,
+}
+
+#[derive(Serialize)]
+struct Component {
+    label: String,
+    temperature_celsius: Option<f32>,
+    max_celsius: Option<f32>,
+    critical_celsius: Option<f32>,
+}
+
+#[derive(Serialize)]
+struct FanSpeed {
+    label: String,
+    rpm: u32,
 }
 
 #[derive(Serialize)]
 struct CommsStatus {
     wifi: String,
     bluetooth_enabled: bool,
-    bluetooth_devices: Vec<String>,
+    bluetooth_devices: Vec<backend::BluetoothDevice>,
     volume_percent: i32,
     brightness_percent: i32,
 }
 
 fn get_system_metrics() -> SystemMetrics {
     let mut sys = System::new_all();
+    let mut net_sampler = NetworkSampler::new();
     std::thread::sleep(std::time::Duration::from_millis(250));
+    sample_system_metrics(&mut sys, &mut net_sampler)
+}
+
+// Tracks the previous `Networks` snapshot and the instant it was taken so
+// per-interface byte deltas can be turned into a rate. In one-shot mode the
+// existing 250ms warm-up sleep is the delta window; in `watch` mode it's
+// whatever the tick interval keeps alive between samples.
+struct NetworkSampler {
+    previous: sysinfo::Networks,
+    last_instant: std::time::Instant,
+}
+
+impl NetworkSampler {
+    fn new() -> Self {
+        Self { previous: sysinfo::Networks::new_with_refreshed_list(), last_instant: std::time::Instant::now() }
+    }
+
+    fn sample(&mut self) -> Vec<NetInterface> {
+        let elapsed = self.last_instant.elapsed().as_secs_f64().max(0.001);
+        let current = sysinfo::Networks::new_with_refreshed_list();
+        let backend = backend::current();
+
+        let mut interfaces = Vec::new();
+        for (name, net) in current.iter() {
+            if name == "lo" || name == "lo0" {
+                continue;
+            }
+            let (is_up, ip_addr) = backend.interface_info(name);
+            if !is_up {
+                continue;
+            }
+            let prev = self.previous.iter().find(|(prev_name, _)| *prev_name == name).map(|(_, prev_net)| prev_net);
+            let prev_rx = prev.map(|p| p.total_received()).unwrap_or_else(|| net.total_received());
+            let prev_tx = prev.map(|p| p.total_transmitted()).unwrap_or_else(|| net.total_transmitted());
+            let rx_bytes_per_sec = net.total_received().saturating_sub(prev_rx) as f64 / elapsed;
+            let tx_bytes_per_sec = net.total_transmitted().saturating_sub(prev_tx) as f64 / elapsed;
+            interfaces.push(NetInterface { name: name.clone(), ip_addr, rx_bytes_per_sec, tx_bytes_per_sec });
+        }
+
+        self.previous = current;
+        self.last_instant = std::time::Instant::now();
+        interfaces
+    }
+
+    fn total_bytes(&self) -> (u64, u64) {
+        self.previous.iter().fold((0, 0), |(rx, tx), (_, net)| (rx + net.total_received(), tx + net.total_transmitted()))
+    }
+}
+
+// Shared by the one-shot `metrics` command and the `watch` daemon's metrics
+// collector thread, which keeps `sys` alive across ticks instead of paying
+// for `System::new_all()` on every sample.
+fn sample_system_metrics(sys: &mut System, net_sampler: &mut NetworkSampler) -> SystemMetrics {
     sys.refresh_cpu_usage();
     sys.refresh_memory();
 
@@ -64,130 +157,301 @@ fn get_system_metrics() -> SystemMetrics {
     let disk_usage_percent = if disk_total > 0 { (disk_used as f64 / disk_total as f64) * 100.0 } else { 0.0 };
 
     // Network
-    let mut network_rx_bytes: u64 = 0;
-    let mut network_tx_bytes: u64 = 0;
-    let nets = sysinfo::Networks::new_with_refreshed_list();
-    for (_name, net) in nets.iter() {
-        network_rx_bytes += net.total_received();
-        network_tx_bytes += net.total_transmitted();
-    }
+    let network_interfaces = net_sampler.sample();
+    let (network_rx_bytes, network_tx_bytes) = net_sampler.total_bytes();
 
-    let (battery_percent, battery_charging) = get_battery_info();
-    let thermal_pressure = get_thermal_pressure();
+    let backend = backend::current();
+    let battery = backend.battery_info();
+    let components = get_components();
+    let thermal_pressure = get_thermal_pressure(&components, &backend);
+    let fans_rpm = get_fan_speeds();
 
     SystemMetrics {
         cpu_usage, cpu_brand, memory_total, memory_used, memory_usage_percent,
         disk_total, disk_used, disk_usage_percent, network_rx_bytes, network_tx_bytes,
-        uptime_seconds, battery_percent, battery_charging, thermal_pressure,
+        uptime_seconds,
+        battery_percent: battery.percent,
+        battery_charging: battery.charging,
+        battery_time_remaining_minutes: battery.time_remaining_minutes,
+        battery_cycle_count: battery.cycle_count,
+        battery_condition: battery.condition,
+        battery_max_capacity_percent: battery.max_capacity_percent,
+        power_source: battery.power_source,
+        thermal_pressure, components, fans_rpm, network_interfaces,
     }
 }
 
-fn get_battery_info() -> (f64, bool) {
-    let output = Command::new("pmset").arg("-g").arg("batt").output();
+// SMC sensor keys differ between Apple Silicon and Intel Macs, and some
+// sensors simply don't report a given reading — sysinfo surfaces those as
+// NaN rather than an error, so treat NaN as "no reading" everywhere.
+fn finite_or_none(value: f32) -> Option<f32> {
+    if value.is_finite() { Some(value) } else { None }
+}
+
+fn get_components() -> Vec<Component> {
+    sysinfo::Components::new_with_refreshed_list()
+        .iter()
+        .map(|c| Component {
+            label: c.label().to_string(),
+            temperature_celsius: finite_or_none(c.temperature()),
+            max_celsius: finite_or_none(c.max()),
+            critical_celsius: c.critical().and_then(finite_or_none),
+        })
+        .collect()
+}
+
+// Best-effort fan RPM reporting. There's no portable sysinfo API for this,
+// so shell out to `istats` when it's installed and fall back to an empty
+// list (same "missing tool → empty/default" shape as the other collectors).
+fn get_fan_speeds() -> Vec<FanSpeed> {
+    let output = Command::new("istats").arg("fan").arg("speed").arg("--value-only").output();
+    let mut fans = Vec::new();
     if let Ok(out) = output {
-        let text = String::from_utf8_lossy(&out.stdout);
-        for line in text.lines() {
-            if line.contains('%') {
-                let parts: Vec<&str> = line.split('\t').collect();
-                if parts.len() >= 2 {
-                    let info = parts[1];
-                    let pct_str: String = info.chars().take_while(|c| c.is_ascii_digit()).collect();
-                    if let Ok(pct) = pct_str.parse::<f64>() {
-                        let charging = info.contains("charging") && !info.contains("discharging");
-                        return (pct, charging);
-                    }
+        for (i, line) in String::from_utf8_lossy(&out.stdout).lines().enumerate() {
+            if let Ok(rpm) = line.trim().parse::<u32>() {
+                fans.push(FanSpeed { label: format!("Fan {}", i), rpm });
+            }
+        }
+    }
+    fans
+}
+
+#[derive(Serialize)]
+struct ProcessInfo {
+    pid: u32,
+    name: String,
+    cpu_usage: f32,
+    memory_bytes: u64,
+    disk_read_bytes: u64,
+    disk_written_bytes: u64,
+    run_time_seconds: u64,
+}
+
+enum ProcessSort {
+    Cpu,
+    Mem,
+}
+
+struct ProcessesArgs {
+    top: usize,
+    sort: ProcessSort,
+}
+
+fn parse_processes_args(args: &[String]) -> ProcessesArgs {
+    let mut top = 10;
+    let mut sort = ProcessSort::Cpu;
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--top" => {
+                if let Some(val) = args.get(i + 1).and_then(|v| v.parse::<usize>().ok()) {
+                    top = val;
+                }
+                i += 1;
+            }
+            "--sort" => {
+                if let Some(val) = args.get(i + 1) {
+                    sort = if val == "mem" { ProcessSort::Mem } else { ProcessSort::Cpu };
                 }
+                i += 1;
             }
+            _ => {}
         }
+        i += 1;
     }
-    (-1.0, false)
+    ProcessesArgs { top, sort }
 }
 
-fn get_thermal_pressure() -> String {
-    let output = Command::new("pmset").arg("-g").arg("therm").output();
-    if let Ok(out) = output {
-        let text = String::from_utf8_lossy(&out.stdout);
-        if text.contains("Normal") { return "NOMINAL".to_string(); }
-        if text.contains("Moderate") { return "MODERATE".to_string(); }
-        if text.contains("Heavy") { return "HEAVY".to_string(); }
-        if text.contains("Critical") { return "CRITICAL".to_string(); }
+// sysinfo's CPU percentages need two samples separated by a delay to mean
+// anything, same as `get_system_metrics`'s 250ms warm-up sleep.
+fn get_processes(args: &ProcessesArgs) -> Vec<ProcessInfo> {
+    let mut sys = System::new_all();
+    sys.refresh_processes();
+    std::thread::sleep(Duration::from_millis(250));
+    sys.refresh_processes();
+
+    let mut processes: Vec<ProcessInfo> = sys
+        .processes()
+        .iter()
+        .map(|(pid, process)| {
+            let disk_usage = process.disk_usage();
+            ProcessInfo {
+                pid: pid.as_u32(),
+                name: process.name().to_string_lossy().to_string(),
+                cpu_usage: process.cpu_usage(),
+                memory_bytes: process.memory(),
+                disk_read_bytes: disk_usage.total_read_bytes,
+                disk_written_bytes: disk_usage.total_written_bytes,
+                run_time_seconds: process.run_time(),
+            }
+        })
+        .collect();
+
+    match args.sort {
+        ProcessSort::Cpu => processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal)),
+        ProcessSort::Mem => processes.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes)),
+    }
+    processes.truncate(args.top);
+    processes
+}
+
+// Derives pressure from the component running hottest relative to its own
+// critical threshold, so it stays meaningful on machines (notably Apple
+// Silicon) where the platform's own thermal API reports nothing. Falls
+// back to the backend's coarse string when no component has both a
+// temperature and a critical threshold to compare against.
+fn get_thermal_pressure(components: &[Component], backend: &impl MetricsBackend) -> String {
+    let hottest_ratio = components
+        .iter()
+        .filter_map(|c| Some((c.temperature_celsius?, c.critical_celsius?)))
+        .filter(|(_, critical)| *critical > 0.0)
+        .map(|(temp, critical)| temp / critical)
+        .fold(None, |acc: Option<f32>, ratio| Some(acc.map_or(ratio, |a| a.max(ratio))));
+
+    if let Some(ratio) = hottest_ratio {
+        return if ratio >= 1.0 {
+            "CRITICAL"
+        } else if ratio >= 0.9 {
+            "HEAVY"
+        } else if ratio >= 0.75 {
+            "MODERATE"
+        } else {
+            "NOMINAL"
+        }
+        .to_string();
     }
-    "NOMINAL".to_string()
+
+    backend.thermal_fallback()
 }
 
 fn get_comms_status() -> CommsStatus {
-    let wifi = get_wifi_info();
-    let (bluetooth_enabled, bluetooth_devices) = get_bluetooth_info();
-    let volume_percent = get_volume();
-    let brightness_percent = get_brightness();
+    let backend = backend::current();
+    let wifi = backend.wifi_info();
+    let (bluetooth_enabled, bluetooth_devices) = backend.bluetooth_info();
+    let volume_percent = backend.volume_percent();
+    let brightness_percent = backend.brightness_percent();
     CommsStatus { wifi, bluetooth_devices, bluetooth_enabled, volume_percent, brightness_percent }
 }
 
-fn get_wifi_info() -> String {
-    let output = Command::new("system_profiler").arg("SPAirPortDataType").output();
-    if let Ok(out) = output {
-        let text = String::from_utf8_lossy(&out.stdout);
-        let mut in_current_network = false;
-        for line in text.lines() {
-            let trimmed = line.trim();
-            if trimmed == "Current Network Information:" { in_current_network = true; continue; }
-            if in_current_network {
-                if trimmed.ends_with(':') && !trimmed.contains("Current Network") {
-                    return trimmed.trim_end_matches(':').to_string();
+// One merged JSON object per tick, combining the latest sample from each
+// collector enabled on the command line. Fields are omitted while a
+// collector hasn't produced its first sample yet.
+#[derive(Serialize)]
+struct WatchRecord<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metrics: Option<&'a SystemMetrics>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comms: Option<&'a CommsStatus>,
+}
+
+enum Sample {
+    Metrics(SystemMetrics),
+    Comms(CommsStatus),
+}
+
+struct WatchArgs {
+    interval_ms: u64,
+    metrics: bool,
+    comms: bool,
+}
+
+fn parse_watch_args(args: &[String]) -> WatchArgs {
+    let mut interval_ms: u64 = 1000;
+    let mut metrics = false;
+    let mut comms = false;
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--interval-ms" => {
+                if let Some(val) = args.get(i + 1).and_then(|v| v.parse::<u64>().ok()) {
+                    interval_ms = val;
                 }
+                i += 1;
             }
+            "--metrics" => metrics = true,
+            "--comms" => comms = true,
+            _ => {}
         }
+        i += 1;
     }
-    "Not Connected".to_string()
+    // No collector flags given → stream everything.
+    if !metrics && !comms {
+        metrics = true;
+        comms = true;
+    }
+    WatchArgs { interval_ms, metrics, comms }
 }
 
-fn get_bluetooth_info() -> (bool, Vec<String>) {
-    let output = Command::new("system_profiler").arg("SPBluetoothDataType").output();
-    if let Ok(out) = output {
-        let text = String::from_utf8_lossy(&out.stdout);
-        let mut enabled = false;
-        let mut devices: Vec<String> = Vec::new();
-        let mut in_connected = false;
-        for line in text.lines() {
-            let trimmed = line.trim();
-            if trimmed.contains("State:") && trimmed.contains("On") { enabled = true; }
-            if trimmed.contains("Bluetooth:") && trimmed.contains("On") { enabled = true; }
-            if trimmed == "Connected:" || trimmed.starts_with("Connected:") { in_connected = true; continue; }
-            if in_connected {
-                if trimmed.is_empty() || trimmed.starts_with("Not Connected:") { in_connected = false; continue; }
-                if trimmed.ends_with(':') && !trimmed.contains("Address") && !trimmed.contains("Services") {
-                    let name = trimmed.trim_end_matches(':').to_string();
-                    if !name.is_empty() && name != "Yes" && name != "No" { devices.push(name); }
+// Keeps a single `System` alive for the life of the daemon instead of
+// re-paying `System::new_all()` + the 250ms warm-up sleep on every poll.
+// One thread per enabled collector samples on its own cadence and sends
+// its struct over `tx`; a `Barrier` lines up everyone's first sample so
+// cached deltas (the per-interface network rates) start from the same
+// window. The dispatcher (this thread) merges the latest of each kind
+// into a `WatchRecord` and prints it once per tick.
+fn run_watch(watch_args: WatchArgs) {
+    let interval = Duration::from_millis(watch_args.interval_ms);
+    let collector_count = watch_args.metrics as usize + watch_args.comms as usize;
+    if collector_count == 0 {
+        return;
+    }
+    let barrier = Arc::new(Barrier::new(collector_count));
+    let (tx, rx) = mpsc::channel::<Sample>();
+
+    if watch_args.metrics {
+        let tx = tx.clone();
+        let barrier = barrier.clone();
+        std::thread::spawn(move || {
+            let mut sys = System::new_all();
+            let mut net_sampler = NetworkSampler::new();
+            std::thread::sleep(Duration::from_millis(250));
+            barrier.wait();
+            loop {
+                let sample = sample_system_metrics(&mut sys, &mut net_sampler);
+                if tx.send(Sample::Metrics(sample)).is_err() {
+                    eprintln!("lcars-metrics: metrics collector's receiver dropped, stopping");
+                    return;
                 }
+                std::thread::sleep(interval);
             }
-        }
-        return (enabled, devices);
+        });
     }
-    (false, Vec::new())
-}
 
-fn get_volume() -> i32 {
-    let output = Command::new("osascript").arg("-e").arg("output volume of (get volume settings)").output();
-    if let Ok(out) = output {
-        let text = String::from_utf8_lossy(&out.stdout).trim().to_string();
-        if let Ok(vol) = text.parse::<i32>() { return vol; }
+    if watch_args.comms {
+        let tx = tx.clone();
+        let barrier = barrier.clone();
+        std::thread::spawn(move || {
+            barrier.wait();
+            loop {
+                let sample = get_comms_status();
+                if tx.send(Sample::Comms(sample)).is_err() {
+                    eprintln!("lcars-metrics: comms collector's receiver dropped, stopping");
+                    return;
+                }
+                std::thread::sleep(interval);
+            }
+        });
     }
-    -1
-}
+    drop(tx);
 
-fn get_brightness() -> i32 {
-    let output = Command::new("bash").arg("-c").arg("ioreg -c AppleBacklightDisplay -r | grep -i brightness | head -1").output();
-    if let Ok(out) = output {
-        let text = String::from_utf8_lossy(&out.stdout);
-        for part in text.split('=') {
-            let trimmed = part.trim().trim_end_matches('}').trim();
-            if let Ok(val) = trimmed.parse::<f64>() {
-                if val <= 1.0 { return (val * 100.0) as i32; }
-                else if val <= 1024.0 { return ((val / 1024.0) * 100.0) as i32; }
+    let mut latest_metrics: Option<SystemMetrics> = None;
+    let mut latest_comms: Option<CommsStatus> = None;
+    loop {
+        std::thread::sleep(interval);
+        loop {
+            match rx.try_recv() {
+                Ok(Sample::Metrics(m)) => latest_metrics = Some(m),
+                Ok(Sample::Comms(c)) => latest_comms = Some(c),
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => return,
             }
         }
+        if latest_metrics.is_none() && latest_comms.is_none() {
+            continue;
+        }
+        let record = WatchRecord { metrics: latest_metrics.as_ref(), comms: latest_comms.as_ref() };
+        println!("{}", serde_json::to_string(&record).unwrap());
     }
-    -1
 }
 
 fn main() {
@@ -203,8 +467,17 @@ fn main() {
             let comms = get_comms_status();
             println!("{}", serde_json::to_string(&comms).unwrap());
         }
+        "watch" => {
+            let watch_args = parse_watch_args(&args[2..]);
+            run_watch(watch_args);
+        }
+        "processes" => {
+            let processes_args = parse_processes_args(&args[2..]);
+            let processes = get_processes(&processes_args);
+            println!("{}", serde_json::to_string(&processes).unwrap());
+        }
         _ => {
-            eprintln!("Usage: lcars-metrics [metrics|comms]");
+            eprintln!("Usage: lcars-metrics [metrics|comms|watch|processes]");
             std::process::exit(1);
         }
     }
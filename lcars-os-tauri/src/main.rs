@@ -5,17 +5,34 @@
 
 use serde::Serialize;
 use std::process::Command;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use sysinfo::System;
 use tauri::State;
 
 struct AppState {
+    // Only ever refreshed on demand by `get_processes`. The metrics sampler
+    // thread deliberately keeps its own private `System` rather than sharing
+    // this one — see the comment in `main()`.
     sys: Mutex<System>,
     comms_cache: Mutex<Option<(Instant, CommsStatus)>>,
+    processes_cache: Mutex<Option<(Instant, Vec<ProcessInfo>)>>,
+    metrics_cache: Mutex<SystemMetrics>,
+    media_session: Mutex<Option<librespot_core::Session>>,
+    now_playing_cache: Mutex<Option<(Instant, NowPlaying)>>,
+    network_sampler: Mutex<NetworkSamplerState>,
 }
 
-#[derive(Serialize)]
+// Tracks the previous `Networks` snapshot and the instant it was taken so
+// `get_network_interfaces` can turn per-interface byte deltas into a rate —
+// same shape as lcars-metrics' own `NetworkSampler`, just kept on `AppState`
+// instead of threaded through a watch loop.
+struct NetworkSamplerState {
+    previous: sysinfo::Networks,
+    last_instant: Instant,
+}
+
+#[derive(Serialize, Clone)]
 struct SystemMetrics {
     cpu_usage: f32,
     cpu_brand: String,
@@ -25,12 +42,22 @@ struct SystemMetrics {
     disk_total: u64,
     disk_used: u64,
     disk_usage_percent: f64,
-    network_rx_bytes: u64,
-    network_tx_bytes: u64,
+    network_rx_rate: f64,
+    network_tx_rate: f64,
+    disk_read_rate: f64,
+    disk_write_rate: f64,
     uptime_seconds: u64,
     battery_percent: f64,
     battery_charging: bool,
     thermal_pressure: String,
+    thermal_components: Vec<ThermalComponent>,
+}
+
+#[derive(Serialize, Clone)]
+struct ThermalComponent {
+    label: String,
+    temperature_c: Option<f32>,
+    critical_c: Option<f32>,
 }
 
 #[derive(Serialize, Clone)]
@@ -50,18 +77,71 @@ struct FileEntry {
     size: u64,
 }
 
-#[tauri::command]
-fn get_system_metrics(state: State<AppState>) -> SystemMetrics {
-    let mut sys = state.sys.lock().unwrap();
+#[derive(Serialize, Clone)]
+struct ProcessInfo {
+    pid: u32,
+    name: String,
+    cpu_usage: f32,
+    memory_bytes: u64,
+    run_time_seconds: u64,
+    parent_pid: Option<u32>,
+}
+
+#[derive(Serialize, Clone)]
+struct NowPlaying {
+    track: String,
+    artist: String,
+    album: String,
+    album_art_url: Option<String>,
+    position_ms: u32,
+    duration_ms: u32,
+    is_playing: bool,
+}
+
+#[derive(Serialize)]
+struct NetInterfaceInfo {
+    name: String,
+    rx_bytes: u64,
+    tx_bytes: u64,
+    rx_rate: f64,
+    tx_rate: f64,
+    is_up: bool,
+}
+
+#[derive(Serialize)]
+struct DiskInfo {
+    name: String,
+    mount_point: String,
+    filesystem: String,
+    total_bytes: u64,
+    available_bytes: u64,
+    used_bytes: u64,
+    is_removable: bool,
+}
+
+// Snapshot of the running totals `sample_metrics` needs to turn into rates
+// on the next tick. Kept across sampler-thread iterations only — nothing
+// else reads it.
+struct RateSample {
+    instant: Instant,
+    rx_bytes: u64,
+    tx_bytes: u64,
+}
+
+// Does the actual refresh + computation; runs once synchronously at startup
+// and then every tick on the background sampler thread. `get_system_metrics`
+// never calls this directly — it just reads whatever the sampler last wrote
+// to `metrics_cache`, so the command itself returns instantly.
+fn sample_metrics(sys: &mut System, previous: &mut Option<RateSample>) -> SystemMetrics {
     sys.refresh_cpu_usage();
     sys.refresh_memory();
+    sys.refresh_processes();
     let cpu_usage = sys.cpus().iter().map(|c| c.cpu_usage()).sum::<f32>() / sys.cpus().len().max(1) as f32;
     let cpu_brand = sys.cpus().first().map(|c| c.brand().to_string()).unwrap_or_else(|| "Unknown".to_string());
     let memory_total = sys.total_memory() as f64 / 1_073_741_824.0;
     let memory_used = sys.used_memory() as f64 / 1_073_741_824.0;
     let memory_usage_percent = if memory_total > 0.0 { (memory_used / memory_total) * 100.0 } else { 0.0 };
     let uptime_seconds = System::uptime();
-    drop(sys);
 
     // Disk
     let mut disk_total: u64 = 0;
@@ -77,24 +157,152 @@ fn get_system_metrics(state: State<AppState>) -> SystemMetrics {
     let disk_usage_percent = if disk_total > 0 { (disk_used as f64 / disk_total as f64) * 100.0 } else { 0.0 };
 
     // Network
-    let mut network_rx_bytes: u64 = 0;
-    let mut network_tx_bytes: u64 = 0;
+    let mut rx_bytes: u64 = 0;
+    let mut tx_bytes: u64 = 0;
     let nets = sysinfo::Networks::new_with_refreshed_list();
     for (_name, net) in nets.iter() {
-        network_rx_bytes += net.total_received();
-        network_tx_bytes += net.total_transmitted();
+        rx_bytes += net.total_received();
+        tx_bytes += net.total_transmitted();
     }
 
+    // sysinfo has no system-wide disk throughput counter, so approximate it by
+    // summing every process's own `disk_usage()`. Unlike the network byte
+    // counters above, `read_bytes`/`written_bytes` are already the delta
+    // since that process's *own* last refresh (not a lifetime total), so the
+    // sum is this tick's system-wide I/O directly — no further diffing
+    // against a previous sample needed, which also sidesteps the bogus
+    // spikes/clamped-to-zero drops a newly-spawned/exited process would
+    // otherwise cause if it contributed its lifetime total on the tick it
+    // first/last appears.
+    let mut disk_read_bytes: u64 = 0;
+    let mut disk_write_bytes: u64 = 0;
+    for (_pid, process) in sys.processes() {
+        let usage = process.disk_usage();
+        disk_read_bytes += usage.read_bytes;
+        disk_write_bytes += usage.written_bytes;
+    }
+
+    let now = Instant::now();
+    let (network_rx_rate, network_tx_rate, disk_read_rate, disk_write_rate) = match previous {
+        Some(prev) => {
+            let elapsed = now.duration_since(prev.instant).as_secs_f64().max(0.001);
+            (
+                rx_bytes.saturating_sub(prev.rx_bytes) as f64 / elapsed,
+                tx_bytes.saturating_sub(prev.tx_bytes) as f64 / elapsed,
+                disk_read_bytes as f64 / elapsed,
+                disk_write_bytes as f64 / elapsed,
+            )
+        }
+        None => (0.0, 0.0, 0.0, 0.0),
+    };
+    *previous = Some(RateSample { instant: now, rx_bytes, tx_bytes });
+
     let (battery_percent, battery_charging) = get_battery_info();
-    let thermal_pressure = get_thermal_pressure();
+    let thermal_components = get_thermal_components();
+    let thermal_pressure = get_thermal_pressure(&thermal_components);
 
     SystemMetrics {
         cpu_usage, cpu_brand, memory_total, memory_used, memory_usage_percent,
-        disk_total, disk_used, disk_usage_percent, network_rx_bytes, network_tx_bytes,
-        uptime_seconds, battery_percent, battery_charging, thermal_pressure,
+        disk_total, disk_used, disk_usage_percent,
+        network_rx_rate, network_tx_rate, disk_read_rate, disk_write_rate,
+        uptime_seconds, battery_percent, battery_charging, thermal_pressure, thermal_components,
     }
 }
 
+#[tauri::command]
+fn get_system_metrics(state: State<Arc<AppState>>) -> SystemMetrics {
+    state.metrics_cache.lock().unwrap().clone()
+}
+
+fn get_interface_is_up(name: &str) -> bool {
+    let output = Command::new("ifconfig").arg(name).output();
+    if let Ok(out) = output {
+        let text = String::from_utf8_lossy(&out.stdout);
+        for line in text.lines() {
+            let trimmed = line.trim();
+            if trimmed.starts_with("flags=") {
+                return trimmed.contains("UP");
+            }
+        }
+    }
+    false
+}
+
+// Per-interface breakdown of the totals `get_system_metrics` collapses into
+// `network_rx_rate`/`network_tx_rate` — skips loopback, but (unlike
+// lcars-metrics' `NetworkSampler`) still reports down interfaces so the UI
+// can actually use `is_up` rather than only ever seeing `true`.
+#[tauri::command]
+fn get_network_interfaces(state: State<Arc<AppState>>) -> Vec<NetInterfaceInfo> {
+    let mut sampler = state.network_sampler.lock().unwrap();
+    let elapsed = sampler.last_instant.elapsed().as_secs_f64().max(0.001);
+    let current = sysinfo::Networks::new_with_refreshed_list();
+
+    let mut interfaces = Vec::new();
+    for (name, net) in current.iter() {
+        if name == "lo" || name == "lo0" {
+            continue;
+        }
+        let is_up = get_interface_is_up(name);
+        let prev = sampler.previous.iter().find(|(prev_name, _)| *prev_name == name).map(|(_, prev_net)| prev_net);
+        let prev_rx = prev.map(|p| p.total_received()).unwrap_or_else(|| net.total_received());
+        let prev_tx = prev.map(|p| p.total_transmitted()).unwrap_or_else(|| net.total_transmitted());
+        interfaces.push(NetInterfaceInfo {
+            name: name.clone(),
+            rx_bytes: net.total_received(),
+            tx_bytes: net.total_transmitted(),
+            rx_rate: net.total_received().saturating_sub(prev_rx) as f64 / elapsed,
+            tx_rate: net.total_transmitted().saturating_sub(prev_tx) as f64 / elapsed,
+            is_up,
+        });
+    }
+
+    sampler.previous = current;
+    sampler.last_instant = Instant::now();
+    interfaces
+}
+
+// Every mounted volume, not just `/` — complements `get_system_metrics`'s
+// single aggregate disk figure.
+#[tauri::command]
+fn get_disks() -> Vec<DiskInfo> {
+    sysinfo::Disks::new_with_refreshed_list()
+        .list()
+        .iter()
+        .map(|disk| {
+            let total_bytes = disk.total_space();
+            let available_bytes = disk.available_space();
+            DiskInfo {
+                name: disk.name().to_string_lossy().to_string(),
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                filesystem: disk.file_system().to_string_lossy().to_string(),
+                total_bytes,
+                available_bytes,
+                used_bytes: total_bytes.saturating_sub(available_bytes),
+                is_removable: disk.is_removable(),
+            }
+        })
+        .collect()
+}
+
+// SMC sensor keys differ between Apple Silicon and Intel Macs, and some
+// sensors simply don't report a given reading — sysinfo surfaces those as
+// NaN rather than an error, so treat NaN as "no reading".
+fn finite_or_none(value: f32) -> Option<f32> {
+    if value.is_finite() { Some(value) } else { None }
+}
+
+fn get_thermal_components() -> Vec<ThermalComponent> {
+    sysinfo::Components::new_with_refreshed_list()
+        .iter()
+        .map(|c| ThermalComponent {
+            label: c.label().to_string(),
+            temperature_c: finite_or_none(c.temperature()),
+            critical_c: c.critical().and_then(finite_or_none),
+        })
+        .collect()
+}
+
 fn get_battery_info() -> (f64, bool) {
     let output = Command::new("pmset").arg("-g").arg("batt").output();
     if let Ok(out) = output {
@@ -116,7 +324,89 @@ fn get_battery_info() -> (f64, bool) {
     (-1.0, false)
 }
 
-fn get_thermal_pressure() -> String {
+// Same 30s-TTL cache shape as `comms_cache` — process listing is cheap
+// enough to refresh, but there's no point re-sorting/truncating on every
+// poll from the UI.
+#[tauri::command]
+fn get_processes(state: State<Arc<AppState>>, top: usize, sort: String) -> Vec<ProcessInfo> {
+    {
+        let cache = state.processes_cache.lock().unwrap();
+        if let Some((timestamp, ref cached)) = *cache {
+            if timestamp.elapsed() < Duration::from_secs(30) {
+                return cached.clone();
+            }
+        }
+    }
+
+    let mut processes: Vec<ProcessInfo> = {
+        let mut sys = state.sys.lock().unwrap();
+        sys.refresh_processes();
+        sys.processes()
+            .iter()
+            .map(|(pid, process)| ProcessInfo {
+                pid: pid.as_u32(),
+                name: process.name().to_string_lossy().to_string(),
+                cpu_usage: process.cpu_usage(),
+                memory_bytes: process.memory(),
+                run_time_seconds: process.run_time(),
+                parent_pid: process.parent().map(|p| p.as_u32()),
+            })
+            .collect()
+    };
+
+    match sort.as_str() {
+        "mem" => processes.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes)),
+        _ => processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal)),
+    }
+    processes.truncate(top);
+
+    let mut cache = state.processes_cache.lock().unwrap();
+    *cache = Some((Instant::now(), processes.clone()));
+    processes
+}
+
+#[tauri::command]
+fn terminate_process(pid: u32, force: bool) -> Result<(), String> {
+    let signal = if force { libc::SIGKILL } else { libc::SIGTERM };
+    let result = unsafe { libc::kill(pid as libc::pid_t, signal) };
+    if result == 0 {
+        return Ok(());
+    }
+    let err = std::io::Error::last_os_error();
+    match err.raw_os_error() {
+        Some(libc::ESRCH) => Err(format!("No such process: {}", pid)),
+        Some(libc::EPERM) => Err(format!("Permission denied signaling process {}", pid)),
+        _ => Err(format!("Failed to signal process {}: {}", pid, err)),
+    }
+}
+
+// Derives pressure from the sensor running hottest relative to its own
+// critical threshold — real per-sensor telemetry instead of the coarse
+// `pmset -g therm` string, and no subprocess on the common path. Falls
+// back to `pmset` when no sensor has both a temperature and a critical
+// threshold to compare against (notably Apple Silicon, where `pmset -g
+// therm` itself reports nothing either).
+fn get_thermal_pressure(components: &[ThermalComponent]) -> String {
+    let hottest_ratio = components
+        .iter()
+        .filter_map(|c| Some((c.temperature_c?, c.critical_c?)))
+        .filter(|(_, critical)| *critical > 0.0)
+        .map(|(temp, critical)| temp / critical)
+        .fold(None, |acc: Option<f32>, ratio| Some(acc.map_or(ratio, |a| a.max(ratio))));
+
+    if let Some(ratio) = hottest_ratio {
+        return if ratio >= 1.0 {
+            "CRITICAL"
+        } else if ratio >= 0.9 {
+            "HEAVY"
+        } else if ratio >= 0.75 {
+            "MODERATE"
+        } else {
+            "NOMINAL"
+        }
+        .to_string();
+    }
+
     let output = Command::new("pmset").arg("-g").arg("therm").output();
     if let Ok(out) = output {
         let text = String::from_utf8_lossy(&out.stdout);
@@ -129,7 +419,7 @@ fn get_thermal_pressure() -> String {
 }
 
 #[tauri::command]
-async fn get_comms_status(state: State<'_, AppState>) -> Result<CommsStatus, String> {
+async fn get_comms_status(state: State<'_, Arc<AppState>>) -> Result<CommsStatus, String> {
     // Check cache (30s TTL)
     {
         let cache = state.comms_cache.lock().unwrap();
@@ -224,6 +514,146 @@ fn get_brightness() -> i32 {
     -1
 }
 
+// Spotify credentials are paired once out-of-band and dropped at
+// `~/.lcars-os/spotify_credentials.json` — same "drop a file in ~/.lcars-os,
+// read it lazily" shape the dictation helper uses for its config.
+fn load_spotify_credentials() -> Result<librespot_core::authentication::Credentials, String> {
+    let home = dirs::home_dir().ok_or("No home directory")?;
+    let path = home.join(".lcars-os").join("spotify_credentials.json");
+    let data = std::fs::read_to_string(&path).map_err(|e| format!("No Spotify credentials at {}: {}", path.display(), e))?;
+    serde_json::from_str(&data).map_err(|e| format!("Malformed Spotify credentials: {}", e))
+}
+
+fn connect_spotify_session() -> Result<librespot_core::Session, String> {
+    let credentials = load_spotify_credentials()?;
+    let session = librespot_core::Session::new(librespot_core::SessionConfig::default(), None);
+    tauri::async_runtime::block_on(session.connect(credentials, true))
+        .map_err(|e| format!("Spotify connect failed: {}", e))?;
+    Ok(session)
+}
+
+// librespot-core only gets us an authenticated `Session`; the actual
+// now-playing/transport surface is simplest reached through the Spotify Web
+// API. Pull a bearer token from the session here, but — unlike the OS/service
+// integrations elsewhere in this file — never hand it to a shelled-out
+// command: argv is readable by any other local user via `ps`/`/proc/<pid>/
+// cmdline`, so a `curl -H "Authorization: Bearer ..."` child process would
+// leak a credential that can read and control the user's playback. Use
+// `reqwest` instead, which puts the header on the wire, not on argv.
+fn get_spotify_token(state: &AppState) -> Result<String, String> {
+    let mut session_guard = state.media_session.lock().unwrap();
+    if session_guard.is_none() {
+        *session_guard = Some(connect_spotify_session()?);
+    }
+    let session = session_guard.as_ref().unwrap();
+    let token = tauri::async_runtime::block_on(
+        session.token_provider().get_token("user-read-playback-state,user-modify-playback-state"),
+    )
+    .map_err(|e| format!("Failed to get Spotify token: {}", e))?;
+    Ok(token.access_token)
+}
+
+fn spotify_api_request(token: &str, method: &str, path: &str) -> Result<String, String> {
+    let url = format!("https://api.spotify.com/v1{}", path);
+    let client = reqwest::blocking::Client::new();
+    let request = match method {
+        "GET" => client.get(&url),
+        "PUT" => client.put(&url),
+        "POST" => client.post(&url),
+        other => return Err(format!("Unsupported HTTP method: {}", other)),
+    };
+    let response = request.bearer_auth(token).send().map_err(|e| format!("Failed to call Spotify API: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("Spotify API request failed: {}", response.status()));
+    }
+    response.text().map_err(|e| format!("Failed to read Spotify response: {}", e))
+}
+
+fn fetch_now_playing(token: &str) -> Result<NowPlaying, String> {
+    let body = spotify_api_request(token, "GET", "/me/player")?;
+    if body.trim().is_empty() {
+        return Err("No active Spotify playback".to_string());
+    }
+    let json: serde_json::Value = serde_json::from_str(&body).map_err(|e| format!("Malformed Spotify response: {}", e))?;
+    let item = json.get("item").ok_or("No track currently playing")?;
+    let track = item.get("name").and_then(|v| v.as_str()).unwrap_or("Unknown").to_string();
+    let artist = item
+        .get("artists")
+        .and_then(|a| a.as_array())
+        .and_then(|a| a.first())
+        .and_then(|a| a.get("name"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("Unknown")
+        .to_string();
+    let album = item.get("album").and_then(|a| a.get("name")).and_then(|v| v.as_str()).unwrap_or("Unknown").to_string();
+    let album_art_url = item
+        .get("album")
+        .and_then(|a| a.get("images"))
+        .and_then(|i| i.as_array())
+        .and_then(|i| i.first())
+        .and_then(|i| i.get("url"))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+    let duration_ms = item.get("duration_ms").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+    let position_ms = json.get("progress_ms").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+    let is_playing = json.get("is_playing").and_then(|v| v.as_bool()).unwrap_or(false);
+    Ok(NowPlaying { track, artist, album, album_art_url, position_ms, duration_ms, is_playing })
+}
+
+// Same short-TTL cache shape as `comms_cache`, just tighter — playback
+// position is expected to move every poll, so 30s would visibly stall it.
+#[tauri::command]
+async fn media_now_playing(state: State<'_, Arc<AppState>>) -> Result<NowPlaying, String> {
+    {
+        let cache = state.now_playing_cache.lock().unwrap();
+        if let Some((timestamp, ref cached)) = *cache {
+            if timestamp.elapsed() < Duration::from_secs(2) {
+                return Ok(cached.clone());
+            }
+        }
+    }
+
+    let inner = state.inner().clone();
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        let token = get_spotify_token(&inner)?;
+        fetch_now_playing(&token)
+    })
+    .await
+    .map_err(|e| format!("Media thread error: {}", e))?;
+
+    let now_playing = result?;
+    {
+        let mut cache = state.now_playing_cache.lock().unwrap();
+        *cache = Some((Instant::now(), now_playing.clone()));
+    }
+    Ok(now_playing)
+}
+
+// `position_ms` is only consulted for the "seek" action; every other action
+// ignores it.
+#[tauri::command]
+async fn media_control(state: State<'_, Arc<AppState>>, action: String, position_ms: Option<u32>) -> Result<(), String> {
+    let inner = state.inner().clone();
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        let token = get_spotify_token(&inner)?;
+        match action.as_str() {
+            "play" => spotify_api_request(&token, "PUT", "/me/player/play").map(|_| ()),
+            "pause" => spotify_api_request(&token, "PUT", "/me/player/pause").map(|_| ()),
+            "next" => spotify_api_request(&token, "POST", "/me/player/next").map(|_| ()),
+            "previous" => spotify_api_request(&token, "POST", "/me/player/previous").map(|_| ()),
+            "seek" => {
+                let position_ms = position_ms.ok_or_else(|| "seek requires a position_ms argument".to_string())?;
+                spotify_api_request(&token, "PUT", &format!("/me/player/seek?position_ms={}", position_ms)).map(|_| ())
+            }
+            other => Err(format!("Unknown media action: {}", other)),
+        }
+    })
+    .await
+    .map_err(|e| format!("Media thread error: {}", e))?;
+
+    result
+}
+
 #[tauri::command]
 fn list_directory(path: String) -> Result<Vec<FileEntry>, String> {
     let dir = if path.is_empty() { dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from("/")) } else { std::path::PathBuf::from(&path) };
@@ -495,14 +925,55 @@ fn main() {
     // Clean up stale dictation files from previous sessions/crashes
     cleanup_dictation_files();
 
-    let sys = System::new_all();
+    // The sampler thread gets its own private `System`, never `AppState.sys`:
+    // `disk_usage().read_bytes`/`written_bytes` are deltas since that
+    // process's *last refresh*, so if `get_processes`'s on-demand
+    // `refresh_processes()` shared this one, a UI poll landing between two
+    // sampler ticks would shorten the window the next tick's disk-rate sum
+    // covers without shortening the `elapsed` it's divided by, silently
+    // under-reporting the rate. Same reasoning lcars-metrics' `NetworkSampler`
+    // and watch-mode collector threads each owning their state already
+    // follows.
+    let mut sampler_sys = System::new_all();
+    let mut rate_sample: Option<RateSample> = None;
+    let initial_metrics = sample_metrics(&mut sampler_sys, &mut rate_sample);
+
+    let state = Arc::new(AppState {
+        sys: Mutex::new(System::new_all()),
+        comms_cache: Mutex::new(None),
+        processes_cache: Mutex::new(None),
+        metrics_cache: Mutex::new(initial_metrics),
+        media_session: Mutex::new(None),
+        now_playing_cache: Mutex::new(None),
+        network_sampler: Mutex::new(NetworkSamplerState {
+            previous: sysinfo::Networks::new_with_refreshed_list(),
+            last_instant: Instant::now(),
+        }),
+    });
+
+    // Refreshes its own `System` on a fixed cadence so `get_system_metrics`
+    // can return the cached snapshot instantly instead of blocking the UI
+    // thread on a full refresh (and the CPU/disk/network sysinfo calls) on
+    // every poll.
+    let sampler_state = state.clone();
+    std::thread::spawn(move || {
+        let mut sys = sampler_sys;
+        let mut rate_sample = rate_sample;
+        loop {
+            std::thread::sleep(Duration::from_secs(1));
+            let metrics = sample_metrics(&mut sys, &mut rate_sample);
+            *sampler_state.metrics_cache.lock().unwrap() = metrics;
+        }
+    });
+
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
-        .manage(AppState { sys: Mutex::new(sys), comms_cache: Mutex::new(None) })
+        .manage(state)
         .invoke_handler(tauri::generate_handler![
             get_system_metrics, list_directory, open_file, get_home_dir,
             get_comms_status, launch_app, save_tasks, load_tasks, save_log, load_log, purge_memory,
-            start_dictation, poll_dictation, stop_dictation
+            start_dictation, poll_dictation, stop_dictation, get_processes, terminate_process,
+            media_now_playing, media_control, get_network_interfaces, get_disks
         ])
         .run(tauri::generate_context!())
         .expect("error while running LCARS OS");